@@ -1,4 +1,7 @@
-use crate::FillError;
+use crate::chunks::ArrayChunks;
+use crate::partial::PartialFill;
+use crate::windows::MapWindows;
+use crate::{FillError, TryFillError};
 
 use core::mem::{self, MaybeUninit};
 use core::ptr;
@@ -59,59 +62,274 @@ where
     }
 }
 
+/// Fills `partial`'s slots from `iter`, stopping once all `N` are written
+/// or `iter` runs dry, whichever comes first. Returns the number of
+/// elements written.
+///
+/// A panic inside `iter::next()` is guarded against: exactly the elements
+/// written so far are dropped. On a normal return, that guard is disarmed
+/// and responsibility for the initialized prefix passes to the caller —
+/// if the returned count is less than `N`, the caller must either drop
+/// `partial`'s prefix itself (see [`drop_filled`]) or hand it off to
+/// something that will, such as [`PartialFill`].
+fn fill<T, const N: usize>(
+    partial: &mut <[T; N] as Array>::PartialArray,
+    iter: impl Iterator<Item = T>,
+) -> usize {
+    let mut guard = ScopeExitGuard {
+        value: partial.as_mut_slice(),
+        data: 0,
+
+        f: move |&len, slice| {
+            let slice: *mut [MaybeUninit<T>] = &mut slice[..len];
+            let slice: *mut [T] = slice as *mut _;
+
+            unsafe { ptr::drop_in_place(slice) }
+        },
+    };
+
+    for (src, dst) in iter.zip(guard.value.iter_mut()) {
+        unsafe {
+            ptr::write(dst, MaybeUninit::new(src));
+            guard.data += 1;
+        }
+    }
+
+    let filled = guard.data;
+    mem::forget(guard);
+
+    filled
+}
+
+/// Like [`fill`], but for an iterator of `Result<T, E>`: stops and returns
+/// `Err(e)` immediately on the first `Err(e)`, without writing it. The
+/// elements written before that point are dropped by the same guard
+/// `fill` uses.
+fn try_fill<T, E, const N: usize>(
+    partial: &mut <[T; N] as Array>::PartialArray,
+    iter: impl Iterator<Item = Result<T, E>>,
+) -> Result<usize, E> {
+    let mut guard = ScopeExitGuard {
+        value: partial.as_mut_slice(),
+        data: 0,
+
+        f: move |&len, slice| {
+            let slice: *mut [MaybeUninit<T>] = &mut slice[..len];
+            let slice: *mut [T] = slice as *mut _;
+
+            unsafe { ptr::drop_in_place(slice) }
+        },
+    };
+
+    for (src, dst) in iter.zip(guard.value.iter_mut()) {
+        match src {
+            Ok(src) => unsafe {
+                ptr::write(dst, MaybeUninit::new(src));
+                guard.data += 1;
+            },
+            // The guard drops `buf[..data]` on its way out here.
+            Err(e) => return Err(e),
+        }
+    }
+
+    let filled = guard.data;
+    mem::forget(guard);
+
+    Ok(filled)
+}
+
+/// Drops the initialized prefix `partial[..len]`. The rest of `partial`,
+/// if any, is left untouched.
+fn drop_filled<T, const N: usize>(partial: &mut <[T; N] as Array>::PartialArray, len: usize) {
+    let slice: *mut [MaybeUninit<T>] = &mut partial.as_mut_slice()[..len];
+    let slice = slice as *mut [T];
+
+    unsafe { ptr::drop_in_place(slice) }
+}
+
+/// Reads `partial` out as a fully-initialized `[T; N]`.
+///
+/// # Safety
+/// Every slot of `partial` must be initialized.
+unsafe fn assume_filled<T, const N: usize>(partial: &<[T; N] as Array>::PartialArray) -> [T; N] {
+    let ptr: *const <[T; N] as Array>::PartialArray = partial;
+    let ptr: *const [T; N] = ptr as *const _;
+    ptr::read(ptr)
+}
+
 impl<T, const N: usize> FromIter<T> for [T; N] {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, FillError> {
-        // First create an uninitialized array of [MaybeUninit<T>; N].
         let mut partial = <[T; N] as Array>::PartialArray::uninit();
+        let filled = fill::<T, N>(&mut partial, iter.into_iter());
 
-        // Then setup a scopeguard,
-        // which should drop any already written items
-        // if there is a panic during collecting,
-        // or if the iterator has less elements than `N`.
-        let mut guard = ScopeExitGuard {
-            value: partial.as_mut_slice(),
-            data: 0,
+        if filled == N {
+            Ok(unsafe { assume_filled(&partial) })
+        } else {
+            drop_filled::<T, N>(&mut partial, filled);
+            Err(FillError::new(filled, N))
+        }
+    }
+}
 
-            f: move |&len, slice| {
-                let slice: *mut [MaybeUninit<T>] = &mut slice[..len];
-                let slice: *mut [T] = slice as *mut _;
+/// Like [`FromIter`], but on underfill the already-written elements are
+/// handed back to the caller instead of being dropped.
+pub trait FromIterOrPartial<T>: Sized {
+    type Partial;
 
-                unsafe { ptr::drop_in_place(slice) }
-            },
-        };
+    fn from_iter_or_partial<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Partial>;
+}
 
-        // Collect
-        for (src, dst) in iter.into_iter().zip(guard.value.iter_mut()) {
-            unsafe {
-                ptr::write(dst, MaybeUninit::new(src));
-                guard.data += 1;
-            }
+impl<T, const N: usize> FromIterOrPartial<T> for [T; N] {
+    type Partial = PartialFill<T, N>;
+
+    fn from_iter_or_partial<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, PartialFill<T, N>> {
+        let mut partial = <[T; N] as Array>::PartialArray::uninit();
+        let filled = fill::<T, N>(&mut partial, iter.into_iter());
+
+        if filled == N {
+            Ok(unsafe { assume_filled(&partial) })
+        } else {
+            // The caller wants the partial data back rather than having it
+            // dropped, so move the buffer + fill count into a `PartialFill`
+            // instead of dropping the prefix ourselves.
+            Err(PartialFill::new(partial, filled))
+        }
+    }
+}
+
+/// Like [`FromIter`], but specialized for iterators whose length is known
+/// up front via [`ExactSizeIterator`].
+///
+/// `iter.len()` is checked against `N` before anything is written, so a
+/// too-short iterator fails immediately instead of writing and then
+/// dropping elements, and a too-long iterator is never exhausted.
+pub trait FromExactIter<T>: Sized {
+    fn from_exact_iter<I: ExactSizeIterator<Item = T>>(iter: I) -> Result<Self, FillError>;
+}
+
+impl<T, const N: usize> FromExactIter<T> for [T; N] {
+    fn from_exact_iter<I: ExactSizeIterator<Item = T>>(iter: I) -> Result<Self, FillError> {
+        if iter.len() != N {
+            // Fail fast: nothing has been written yet.
+            return Err(FillError::new(0, N));
         }
 
-        // if we wrote `N` items, we're good,
-        // so make sure the guard doesnt drop,
-        // and return the array.
-        if guard.data == N {
-            guard.value = &mut [];
-            guard.data = 0;
+        let mut partial = <[T; N] as Array>::PartialArray::uninit();
+        let filled = fill::<T, N>(&mut partial, iter);
 
-            mem::forget(guard);
+        if filled == N {
+            Ok(unsafe { assume_filled(&partial) })
+        } else {
+            // `ExactSizeIterator::len` is a safe trait method and can't be
+            // trusted for soundness: a safe but lying impl could report
+            // `len() == N` while actually yielding fewer items. Treat that
+            // exactly like `FromIter`'s underfill case instead of assuming
+            // the array is fully written.
+            drop_filled::<T, N>(&mut partial, filled);
+            Err(FillError::new(filled, N))
+        }
+    }
+}
 
-            let array: [T; N] = unsafe {
-                let ptr: *const [MaybeUninit<T>; N] = &partial;
-                let ptr: *const [T; N] = ptr as _;
-                ptr::read(ptr)
-            };
+/// Like [`FromIter`], but for iterators of `Result<T, E>`, short-circuiting
+/// on the first `Err`.
+pub trait TryFromIter<T, E>: Sized {
+    fn try_from_iter<I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Result<Self, TryFillError<E>>;
+}
+
+impl<T, E, const N: usize> TryFromIter<T, E> for [T; N] {
+    fn try_from_iter<I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Result<Self, TryFillError<E>> {
+        let mut partial = <[T; N] as Array>::PartialArray::uninit();
+        let filled = try_fill::<T, E, N>(&mut partial, iter.into_iter()).map_err(TryFillError::Err)?;
 
-            Ok(array)
+        if filled == N {
+            Ok(unsafe { assume_filled(&partial) })
         } else {
-            // We're not good, so return an error.
-            // The dropguard will run here.
-            Err(FillError::new(guard.data, N))
+            drop_filled::<T, N>(&mut partial, filled);
+            Err(TryFillError::Incomplete(FillError::new(filled, N)))
         }
     }
 }
 
+/// Builds an array of type `Self` by calling `f` with each index in
+/// `0..N`, in order.
+pub trait FromFn<T>: Sized {
+    fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self;
+}
+
+impl<T, const N: usize> FromFn<T> for [T; N] {
+    fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> Self {
+        let mut partial = <[T; N] as Array>::PartialArray::uninit();
+
+        // `0..N` is an iterator of exactly `N` items, so `fill` always
+        // writes every slot here; `f` being infallible, the only thing
+        // `fill`'s guard protects against is `f` panicking partway through.
+        fill::<T, N>(&mut partial, (0..N).map(&mut f));
+
+        unsafe { assume_filled(&partial) }
+    }
+}
+
+/// Like [`FromFn`], but `f` may fail, short-circuiting the construction on
+/// the first `Err`.
+pub trait TryFromFn<T, E>: Sized {
+    fn try_from_fn<F: FnMut(usize) -> Result<T, E>>(f: F) -> Result<Self, E>;
+}
+
+impl<T, E, const N: usize> TryFromFn<T, E> for [T; N] {
+    fn try_from_fn<F: FnMut(usize) -> Result<T, E>>(mut f: F) -> Result<Self, E> {
+        let mut partial = <[T; N] as Array>::PartialArray::uninit();
+
+        // `0..N` is an iterator of exactly `N` items, so success here
+        // always means a fully-written array; `try_fill` short-circuits
+        // (and cleans up via its own guard) on the first `Err`.
+        try_fill::<T, E, N>(&mut partial, (0..N).map(&mut f))?;
+
+        Ok(unsafe { assume_filled(&partial) })
+    }
+}
+
+/// Builds an array by calling `f` with each index in `0..N`.
+///
+/// # Examples
+/// ```
+/// use arraycollect::array_from_fn;
+///
+/// let array: [usize; 5] = array_from_fn(|idx| idx * 2);
+/// assert_eq!(array, [0, 2, 4, 6, 8]);
+/// ```
+pub fn array_from_fn<T, A, F>(f: F) -> A
+where
+    A: FromFn<T>,
+    F: FnMut(usize) -> T,
+{
+    A::from_fn(f)
+}
+
+/// Builds an array by calling `f` with each index in `0..N`, short-circuiting
+/// on the first `Err`.
+///
+/// # Examples
+/// ```
+/// use arraycollect::try_from_fn;
+///
+/// let array: Result<[u8; 4], &str> = try_from_fn(|idx| {
+///     if idx < 3 {
+///         Ok(idx as u8)
+///     } else {
+///         Err("too large")
+///     }
+/// });
+/// assert_eq!(array, Err("too large"));
+/// ```
+pub fn try_from_fn<T, E, A, F>(f: F) -> Result<A, E>
+where
+    A: TryFromFn<T, E>,
+    F: FnMut(usize) -> Result<T, E>,
+{
+    A::try_from_fn(f)
+}
+
 pub trait IntoArray: Iterator {
     fn array_collect<A: FromIter<Self::Item>>(self) -> Result<A, FillError>
     where
@@ -119,6 +337,150 @@ pub trait IntoArray: Iterator {
     {
         A::from_iter(self)
     }
+
+    /// Collects an iterator of `Result<T, E>` into an array, short-circuiting
+    /// on the first `Err`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arraycollect::{IntoArray, TryFillError};
+    ///
+    /// let array = [Ok(1), Ok(2), Ok(3)]
+    ///     .into_iter()
+    ///     .try_array_collect::<_, (), [_; 3]>();
+    /// assert_eq!(array, Ok([1, 2, 3]));
+    ///
+    /// let array = [Ok(1), Err("bad"), Ok(3)]
+    ///     .into_iter()
+    ///     .try_array_collect::<_, _, [_; 3]>();
+    /// assert!(matches!(array, Err(TryFillError::Err("bad"))));
+    /// ```
+    fn try_array_collect<T, E, A>(self) -> Result<A, TryFillError<E>>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        A: TryFromIter<T, E>,
+    {
+        A::try_from_iter(self)
+    }
+
+    /// Collects an iterator of `Option<T>` into an array, short-circuiting
+    /// as soon as a `None` is encountered.
+    ///
+    /// # Examples
+    /// ```
+    /// use arraycollect::IntoArray;
+    ///
+    /// let array = [Some(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .try_array_collect_option::<_, [_; 3]>();
+    /// assert_eq!(array, Some([1, 2, 3]));
+    ///
+    /// let array = [Some(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .try_array_collect_option::<_, [_; 3]>();
+    /// assert_eq!(array, None);
+    /// ```
+    fn try_array_collect_option<T, A>(self) -> Option<A>
+    where
+        Self: Sized + Iterator<Item = Option<T>>,
+        A: TryFromIter<T, ()>,
+    {
+        A::try_from_iter(self.map(|item| item.ok_or(()))).ok()
+    }
+
+    /// Collects the iterator into an array, but on underfill hands back the
+    /// already-written elements instead of dropping them.
+    ///
+    /// # Examples
+    /// ```
+    /// use arraycollect::IntoArray;
+    ///
+    /// let array: [_; 10] = (0..10).array_collect_or_partial().unwrap();
+    /// assert_eq!(array, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let partial = (0..3).array_collect_or_partial::<[_; 5]>().unwrap_err();
+    /// assert_eq!(partial.len(), 3);
+    /// assert_eq!(partial.array_collect::<[_; 3]>(), Ok([0, 1, 2]));
+    /// ```
+    fn array_collect_or_partial<A: FromIterOrPartial<Self::Item>>(self) -> Result<A, A::Partial>
+    where
+        Self: Sized,
+    {
+        A::from_iter_or_partial(self)
+    }
+
+    /// Collects the iterator into an array, using the iterator's
+    /// [`ExactSizeIterator::len`] to fail fast on a length mismatch instead
+    /// of writing and then dropping elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use arraycollect::{FillError, IntoArray};
+    ///
+    /// let array = (0..5).array_collect_exact::<[_; 5]>();
+    /// assert_eq!(array, Ok([0, 1, 2, 3, 4]));
+    ///
+    /// // the length mismatch is caught before anything is written.
+    /// let array = (0..3).array_collect_exact::<[_; 5]>();
+    /// assert_eq!(array, Err(FillError::new(0, 5)));
+    /// ```
+    fn array_collect_exact<A: FromExactIter<Self::Item>>(self) -> Result<A, FillError>
+    where
+        Self: Sized + ExactSizeIterator,
+    {
+        A::from_exact_iter(self)
+    }
+
+    /// Splits the iterator into successive non-overlapping `[Item; N]`
+    /// chunks.
+    ///
+    /// If the number of elements is not a multiple of `N`, the last
+    /// elements are buffered rather than yielded; recover them with
+    /// [`ArrayChunks::into_remainder`].
+    ///
+    /// # Examples
+    /// ```
+    /// use arraycollect::IntoArray;
+    ///
+    /// let mut chunks = (0..7).array_chunks::<3>();
+    /// assert_eq!(chunks.next(), Some([0, 1, 2]));
+    /// assert_eq!(chunks.next(), Some([3, 4, 5]));
+    /// assert_eq!(chunks.next(), None);
+    ///
+    /// let mut remainder = chunks.into_remainder();
+    /// assert_eq!(remainder.next(), Some(6));
+    /// assert_eq!(remainder.next(), None);
+    /// ```
+    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N>
+    where
+        Self: Sized,
+    {
+        ArrayChunks::new(self)
+    }
+
+    /// Yields overlapping `[Item; N]` windows over the last `N` items,
+    /// advancing one element per step. Complements [`array_chunks`], which
+    /// yields non-overlapping chunks instead.
+    ///
+    /// [`array_chunks`]: IntoArray::array_chunks
+    ///
+    /// # Examples
+    /// ```
+    /// use arraycollect::IntoArray;
+    ///
+    /// let mut windows = (0..5).map_windows::<3>();
+    /// assert_eq!(windows.next(), Some([0, 1, 2]));
+    /// assert_eq!(windows.next(), Some([1, 2, 3]));
+    /// assert_eq!(windows.next(), Some([2, 3, 4]));
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    fn map_windows<const N: usize>(self) -> MapWindows<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        MapWindows::new(self)
+    }
 }
 
 impl<I: Iterator> IntoArray for I {}
@@ -0,0 +1,90 @@
+use crate::array::{Array, PartialArray};
+use crate::partial::PartialFill;
+
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+
+/// An iterator over non-overlapping `[Item; N]` chunks of the underlying
+/// iterator, created by [`IntoArray::array_chunks`].
+///
+/// [`IntoArray::array_chunks`]: crate::IntoArray::array_chunks
+pub struct ArrayChunks<I: Iterator, const N: usize> {
+    iter: I,
+    buf: <[I::Item; N] as Array>::PartialArray,
+    filled: usize,
+}
+
+impl<I: Iterator, const N: usize> ArrayChunks<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        const { assert!(N != 0, "ArrayChunks requires a non-zero chunk size") };
+
+        Self {
+            iter,
+            buf: <[I::Item; N] as Array>::PartialArray::uninit(),
+            filled: 0,
+        }
+    }
+
+    /// Consumes the adapter, returning an iterator over the elements of the
+    /// trailing chunk that didn't fill up to `N`, in order.
+    ///
+    /// If the underlying iterator was exhausted exactly on a chunk boundary,
+    /// the returned iterator yields no elements.
+    pub fn into_remainder(self) -> PartialFill<I::Item, N> {
+        // `self` implements `Drop`, so its fields can't be moved out of
+        // directly. Suppress the glue with `ManuallyDrop`, then take the
+        // buffer by value and drop the source iterator ourselves.
+        let mut this = mem::ManuallyDrop::new(self);
+
+        let filled = this.filled;
+        let buf: [MaybeUninit<I::Item>; N] = unsafe {
+            let ptr: *const <[I::Item; N] as Array>::PartialArray = &this.buf;
+            let ptr: *const [MaybeUninit<I::Item>; N] = ptr as *const _;
+            ptr::read(ptr)
+        };
+
+        unsafe { ptr::drop_in_place(&mut this.iter) };
+
+        PartialFill::new(buf, filled)
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.filled < N {
+            match self.iter.next() {
+                Some(item) => unsafe {
+                    ptr::write(&mut self.buf.as_mut_slice()[self.filled], MaybeUninit::new(item));
+                    self.filled += 1;
+                },
+                None => return None,
+            }
+        }
+
+        self.filled = 0;
+
+        let array: [I::Item; N] = unsafe {
+            let ptr: *const <[I::Item; N] as Array>::PartialArray = &self.buf;
+            let ptr: *const [I::Item; N] = ptr as *const _;
+            ptr::read(ptr)
+        };
+
+        Some(array)
+    }
+}
+
+impl<I: Iterator, const N: usize> Drop for ArrayChunks<I, N> {
+    fn drop(&mut self) {
+        let len = self.filled;
+
+        let ptr: *mut MaybeUninit<I::Item> = self.buf.as_mut_slice()[..len].as_mut_ptr();
+        let ptr = ptr as *mut I::Item;
+
+        unsafe {
+            let slice = core::slice::from_raw_parts_mut(ptr, len);
+            ptr::drop_in_place::<[I::Item]>(slice);
+        }
+    }
+}
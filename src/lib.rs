@@ -16,6 +16,18 @@
 //! get dropped, and an error is returned.
 //! If it's completely filled, the array is returned.
 
+mod array;
+pub use crate::array::*;
+
+mod chunks;
+pub use crate::chunks::*;
+
+mod partial;
+pub use crate::partial::*;
+
+mod windows;
+pub use crate::windows::*;
+
 #[macro_export]
 macro_rules! uninit_array {
     ($tgt:ty; $size:expr) => {
@@ -56,6 +68,40 @@ impl core::fmt::Display for FillError {
 #[cfg(feature = "std")]
 impl std::error::Error for FillError {}
 
+/// The error returned by the fallible collection methods on [`IntoArray`],
+/// such as `try_array_collect`.
+///
+/// Either the source iterator yielded an error before the array could be
+/// filled (`Err`), or the iterator was drained early with no error of its
+/// own (`Incomplete`), in which case a plain [`FillError`] is carried
+/// instead.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum TryFillError<E> {
+    Err(E),
+    Incomplete(FillError),
+}
+
+impl<E: core::fmt::Debug> core::fmt::Debug for TryFillError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            TryFillError::Err(e) => f.write_fmt(format_args!("Iterator yielded an error: {:?}", e)),
+            TryFillError::Incomplete(err) => core::fmt::Debug::fmt(err, f),
+        }
+    }
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for TryFillError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            TryFillError::Err(e) => f.write_fmt(format_args!("Iterator yielded an error: {}", e)),
+            TryFillError::Incomplete(err) => core::fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for TryFillError<E> {}
+
 /// Tries to collect `$iter` into an array of type `[$tgt; $size]`.
 /// If the iterator yields less than `$size` elements, and error is returned.
 ///
@@ -0,0 +1,75 @@
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// An owned, partially-initialized `[T; N]` buffer: the first `len`
+/// elements are initialized, the rest are not.
+///
+/// Returned by [`ArrayChunks::into_remainder`] for the trailing elements
+/// that didn't fill a full chunk, and by
+/// [`IntoArray::array_collect_or_partial`] for the elements written before
+/// the source iterator ran dry.
+///
+/// [`ArrayChunks::into_remainder`]: crate::ArrayChunks::into_remainder
+/// [`IntoArray::array_collect_or_partial`]: crate::IntoArray::array_collect_or_partial
+pub struct PartialFill<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    idx: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> PartialFill<T, N> {
+    /// `buf[..len]` must be initialized; `buf[len..]` must not be.
+    pub(crate) fn new(buf: [MaybeUninit<T>; N], len: usize) -> Self {
+        Self { buf, idx: 0, len }
+    }
+
+    /// The number of initialized elements left to yield.
+    pub fn len(&self) -> usize {
+        self.len - self.idx
+    }
+
+    /// Whether there are no initialized elements left to yield.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T, const N: usize> Iterator for PartialFill<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx < self.len {
+            let item = unsafe { ptr::read(self.buf[self.idx].as_ptr()) };
+            self.idx += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for PartialFill<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("PartialFill")
+            .field("filled", &self.len())
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+impl<T, const N: usize> Drop for PartialFill<T, N> {
+    fn drop(&mut self) {
+        let ptr: *mut MaybeUninit<T> = self.buf[self.idx..self.len].as_mut_ptr();
+        let ptr = ptr as *mut T;
+
+        unsafe {
+            let slice = core::slice::from_raw_parts_mut(ptr, self.len - self.idx);
+            ptr::drop_in_place::<[T]>(slice);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::array::{array_from_fn, Array, PartialArray};
+
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// An iterator over overlapping `[Item; N]` windows of the last `N` items,
+/// created by [`IntoArray::map_windows`].
+///
+/// [`IntoArray::map_windows`]: crate::IntoArray::map_windows
+pub struct MapWindows<I, const N: usize>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    iter: I,
+    buf: <[I::Item; N] as Array>::PartialArray,
+    // Index of the oldest live element; the window is read out starting here.
+    head: usize,
+    filled: usize,
+}
+
+impl<I, const N: usize> MapWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        const { assert!(N != 0, "MapWindows requires a non-zero window size") };
+
+        Self {
+            iter,
+            buf: <[I::Item; N] as Array>::PartialArray::uninit(),
+            head: 0,
+            filled: 0,
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for MapWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.filled < N {
+            // Prime the ring buffer with the first `N` items.
+            while self.filled < N {
+                match self.iter.next() {
+                    Some(item) => unsafe {
+                        ptr::write(&mut self.buf.as_mut_slice()[self.filled], MaybeUninit::new(item));
+                        self.filled += 1;
+                    },
+                    // Source shorter than `N`: nothing to drop beyond
+                    // `buf[..filled]`, which our `Drop` impl already handles.
+                    None => return None,
+                }
+            }
+        } else {
+            // Pull the next element, drop the oldest live slot, and write
+            // the new element in its place.
+            let item = self.iter.next()?;
+
+            unsafe {
+                let oldest: *mut MaybeUninit<I::Item> = &mut self.buf.as_mut_slice()[self.head];
+                ptr::drop_in_place(oldest as *mut I::Item);
+                ptr::write(oldest, MaybeUninit::new(item));
+            }
+
+            self.head = (self.head + 1) % N;
+        }
+
+        let head = self.head;
+        let ptr: *const MaybeUninit<I::Item> = self.buf.as_mut_slice().as_ptr();
+
+        Some(array_from_fn(|idx| {
+            let pos = (head + idx) % N;
+            unsafe { (*ptr.add(pos)).assume_init_ref().clone() }
+        }))
+    }
+}
+
+impl<I, const N: usize> Drop for MapWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    fn drop(&mut self) {
+        // Once primed, every slot is live (just rotated); while priming,
+        // only the contiguous prefix `buf[..filled]` is.
+        let len = self.filled;
+
+        let ptr: *mut MaybeUninit<I::Item> = self.buf.as_mut_slice()[..len].as_mut_ptr();
+        let ptr = ptr as *mut I::Item;
+
+        unsafe {
+            let slice = core::slice::from_raw_parts_mut(ptr, len);
+            ptr::drop_in_place::<[I::Item]>(slice);
+        }
+    }
+}